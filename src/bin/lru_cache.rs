@@ -34,11 +34,12 @@ fn main() {
 
     println!("\n--- Write cache dans le fichier {} ---", file_path);
     // Écriture du cache dans le fichier
-    FilePersistence::write_file(&cache, &file_path);
+    FilePersistence::write_file(&cache, &file_path).expect("écriture du cache");
 
     println!("\n--- Lecture du cache depuis {} ---", file_path);
     // Lecture du cache depuis le fichier
-    let mut loaded_cache1: Cache<String, String> = FilePersistence::read_file(3, &file_path);
+    let mut loaded_cache1: Cache<String, String> =
+        FilePersistence::read_file(3, &file_path).expect("lecture du cache");
     println!("Cache chargé : {:?}", loaded_cache1.elements); //[A,D,C]
 
     println!("\n--- Ajout de X et get de D pour changer l'ordre et éjecté A ---");
@@ -49,10 +50,11 @@ fn main() {
 
     println!("\n--- Write cache modifié dans le fichier {} ---", file_path);
     // Écriture du cache modifié dans le fichier
-    FilePersistence::write_file(&loaded_cache1, &file_path);
+    FilePersistence::write_file(&loaded_cache1, &file_path).expect("écriture du cache");
 
     println!("\n--- Lecture du cache depuis {} ---", file_path);
     // Lecture du cache depuis le fichier
-    let loaded_cache2: Cache<String, String> = FilePersistence::read_file(3, &file_path);
+    let loaded_cache2: Cache<String, String> =
+        FilePersistence::read_file(3, &file_path).expect("lecture du cache");
     println!("Cache chargé: {:?}", loaded_cache2.elements); // [C,X,D]
 }
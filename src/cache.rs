@@ -3,11 +3,16 @@
 //! Ce module définit une structure `Cache` qui stocke des paires clé-valeur
 //! dont la paire la plus ancienne (least recently used) est éjecté
 //! lorsque la capacité maximale du cache est dépassée.
-//! Un index global (`max_index`) est mis à jour à chaque `get` et `put` pour
-//! déterminer l'élément le moins récemment utilisé.
+//! L'ordre de récence est maintenu par une liste doublement chaînée *filée
+//! à travers la `HashMap` elle-même* : chaque `Element` connaît la clé de son
+//! voisin précédent et suivant (`prev`/`next`), et le `Cache` garde la tête
+//! (`head`, le plus récemment utilisé) et la queue (`tail`, le moins récemment
+//! utilisé) de cette liste. `get` et `put` replacent ainsi l'élément concerné
+//! en tête en O(1), sans pointeurs bruts ni compteur d'index global.
 
+use std::collections::hash_map::RandomState;
 use std::collections::HashMap;
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash};
 
 /// # Exemple
 ///
@@ -18,12 +23,12 @@ use std::hash::Hash;
 /// let mut cache: Cache<String, String> = Cache::new(2);
 /// cache.put("A".to_string(), "1".to_string());
 /// cache.put("B".to_string(), "2".to_string());
-/// // cache = {A:1, B:2}
+/// // cache = [B, A] (B en tête, A en queue)
 /// assert_eq!(cache.get(&"A".to_string()), Some(&"1".to_string()));
-/// // Get de A : son index est mis à jour (cache = {B:2, A:1})
+/// // Get de A : il repasse en tête (cache = [A, B])
 /// cache.put("C".to_string(), "3".to_string());
-/// // Capacité de 2 dépassée, l'élément B (le moins récemment utilisé) est éjecté
-/// // cache = {A:1, C:3}
+/// // Capacité de 2 dépassée, l'élément B (en queue, le moins récemment utilisé) est éjecté
+/// // cache = [C, A]
 /// assert_eq!(cache.get(&"B".to_string()), None);
 /// assert_eq!(cache.get(&"C".to_string()), Some(&"3".to_string()));
 /// ```
@@ -31,19 +36,30 @@ use std::hash::Hash;
 
 /// Cette structure représente un élément stocké dans le cache LRU.
 ///
-/// `Element` contient la valeur ainsi qu'un index indiquant sa position dans l'ordre LRU.
+/// `Element` contient la valeur ainsi que les clés de ses voisins dans la
+/// liste chaînée d'ordre LRU : `prev` (vers l'élément plus récent) et `next`
+/// (vers l'élément plus ancien). `None` signale une extrémité de la liste.
+/// `weight` est le poids de l'élément au sens de [`WeightScale`] ; il vaut
+/// toujours `0` pour un cache créé via [`Cache::new`] (mode à capacité en
+/// nombre d'éléments), et reflète `scale.weight(&value)` pour un élément
+/// inséré via [`Cache::put_with_weight`].
 #[derive(Debug, Clone)]
-pub struct Element<V> {
-    pub index: usize,
+pub struct Element<K, V> {
     pub value: V,
+    pub prev: Option<K>,
+    pub next: Option<K>,
+    pub weight: usize,
 }
 
 /// Cache LRU (Least Recently Used) générique associant des clés de type `K` à des valeurs de type `V`.
 ///
 /// Le cache a une capacité fixe (`size`). Lorsqu'on insère un nouvel élément au-delà de cette capacité,
-/// l'élément le moins récemment utilisé (celui avec l'`index` le plus petit) est automatiquement évincé.
+/// l'élément le moins récemment utilisé (celui en queue de la liste chaînée, `tail`) est automatiquement
+/// éjecté.
 ///
-/// Les opérations `get` et `put` mettent à jour l'index interne (`max_index`) pour maintenir l'ordre LRU.
+/// Les opérations `get` et `put` replacent l'élément concerné en tête (`head`) de la liste pour
+/// maintenir l'ordre LRU, en ne patchant que les clés voisines touchées : ces deux opérations sont
+/// donc amorties en O(1).
 ///
 /// # Exemple
 ///
@@ -54,21 +70,55 @@ pub struct Element<V> {
 /// let mut cache: Cache<String, String> = Cache::new(2);
 /// cache.put("A".to_string(), "1".to_string());
 /// cache.put("B".to_string(), "2".to_string());
-/// // cache = {A:{index:0, value:"1"}, B:{index:1, value:"2"}
+/// // cache = [B, A] (B en tête, A en queue)
 /// assert_eq!(cache.get(&"A".to_string()), Some(&"1".to_string()));
-/// // Get de A : son index est mis à jour (cache = {B:{index:1, value:"2"}, A:{index:2, value:"1"})
+/// // Get de A : il repasse en tête (cache = [A, B])
 /// cache.put("C".to_string(), "3".to_string());
-/// // Capacité de 2 dépassée, l'élément B (le moins récemment utilisé) est éjecté
-/// // cache = {A:{index:2, value:"1"}, C:{index:3, value:"3"}
+/// // Capacité de 2 dépassée, l'élément B (en queue) est éjecté
+/// // cache = [C, A]
 /// assert_eq!(cache.get(&"B".to_string()), None);
 /// assert_eq!(cache.get(&"C".to_string()), Some(&"3".to_string()));
 /// ```
 ///
 #[derive(Debug, Clone)]
-pub struct Cache<K, V> {
-    pub elements: HashMap<K, Element<V>>,
+pub struct Cache<K, V, S = ZeroWeightScale, H = RandomState> {
+    pub elements: HashMap<K, Element<K, V>, H>,
     pub size: usize,
-    pub max_index: usize,
+    pub head: Option<K>,
+    pub tail: Option<K>,
+    scale: S,
+    /// Somme courante de `elements[_].weight`, maintenue à jour à chaque insertion/éviction
+    /// pour que [`Cache::put_with_weight`] puisse vérifier l'invariant `total_weight <= size`
+    /// en O(1), sans reparcourir la `HashMap`.
+    total_weight: usize,
+}
+
+/// Erreurs pouvant survenir lors des opérations sur un `Cache`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheError {
+    /// Le poids de l'élément à insérer dépasse à lui seul la capacité du cache :
+    /// aucune éviction ne peut le faire tenir.
+    TooHeavy,
+    /// La capacité demandée est nulle : un tel cache ne pourrait jamais retenir un élément
+    /// (voir [`Cache::try_new`]).
+    ZeroCapacity,
+}
+
+/// Calcule le poids (coût mémoire) d'une valeur pour le mode de capacité pondérée
+/// d'un `Cache` (voir [`Cache::with_weight`] et [`Cache::put_with_weight`]).
+pub trait WeightScale<V> {
+    fn weight(&self, value: &V) -> usize;
+}
+
+/// Échelle de poids par défaut : chaque valeur pèse `0`, ce qui revient à borner
+/// uniquement le nombre d'éléments — le comportement historique de `Cache::new`/`put`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ZeroWeightScale;
+
+impl<V> WeightScale<V> for ZeroWeightScale {
+    fn weight(&self, _value: &V) -> usize {
+        0
+    }
 }
 
 /// Trait définissant les opérations principales d'un cache LRU.
@@ -86,13 +136,16 @@ pub trait CacheTrait<K, V> {
     ///
     /// let cache: Cache<String, String> = Cache::new(3);
     /// assert_eq!(cache.size, 3);
-    /// assert_eq!(cache.max_index, 0);
     /// assert!(cache.elements.is_empty());
+    /// assert_eq!(cache.head, None);
+    /// assert_eq!(cache.tail, None);
     /// ```
-    fn new(size: usize) -> Self;
+    fn new(size: usize) -> Self
+    where
+        Self: Sized;
 
     /// Récupère la valeur associée à la clé spécifiée, si elle existe.
-    /// Cette opération met à jour l'index LRU de l'élément respecter l'ordre LRU.
+    /// Cette opération replace l'élément en tête de la liste LRU (le plus récemment utilisé).
     /// Renvoie `None` si la clé n'existe pas dans le cache.
     ///
     /// # Exemple
@@ -102,21 +155,21 @@ pub trait CacheTrait<K, V> {
     /// let mut cache = Cache::new(2);
     /// cache.put("X".to_string(), 10);
     /// cache.put("Y".to_string(), 20);
-    /// // cache = {X:{index:1, value:10}, Y:{index:2, value:20}}
-    /// // Après le get, l'index de X passe de 1 à 3 pour passer devant Y d'index 2
+    /// // cache = [Y, X]
+    /// // Après le get, X repasse devant Y : cache = [X, Y]
     /// assert_eq!(cache.get(&"X".to_string()), Some(&10));
-    /// // La clef A n'existe pas, None est retourné et aucun index n'est mis à jour
+    /// // La clef A n'existe pas, None est retourné et l'ordre n'est pas modifié
     /// assert_eq!(cache.get(&"A".to_string()), None);
     /// ```
     fn get(&mut self, key: &K) -> Option<&V>;
 
     /// Insère ou met à jour une paire clé-valeur dans le cache.
     ///
-    /// - Si la clé existe déjà, la valeur est mise à jour et l'index de l'élément est incrémenté.
+    /// - Si la clé existe déjà, la valeur est mise à jour et l'élément est replacé en tête.
     ///   La valeur précédente est alors retournée (`Some(ancienne_valeur)`).
-    /// - Si la clé n'existe pas et que le cache est plein, l'élément le moins récemment utilisé
-    ///   (celui avec l'index le plus petit) est éjecté.
-    /// - L'ajout d'un nouvel élément (ou la mise à jour) incrémente toujours l'index global.
+    /// - Si la clé n'existe pas et que le cache est plein, l'élément en queue de liste
+    ///   (le moins récemment utilisé) est éjecté.
+    /// - Le nouvel élément (ou l'élément mis à jour) est toujours placé en tête de liste.
     ///
     /// Retourne `None` si on ajoute un nouvel élément, ou `Some(ancienne_valeur)` si on met à jour une valeur existante.
     ///
@@ -127,18 +180,38 @@ pub trait CacheTrait<K, V> {
     /// let mut cache = Cache::new(2);
     /// assert_eq!(cache.put("A".to_string(), 1), None);
     /// assert_eq!(cache.put("B".to_string(), 2), None);
-    /// // cache = {A:{index:1, value:1}, B:{index:2, value:2}}
+    /// // cache = [B, A]
     /// assert_eq!(cache.put("A".to_string(), 3), Some(1));
-    /// // Mise à jour de A: ancienne valeur 1 retournée
-    /// // cache = {A:{index:3, value:3}, B:{index:2, value:2}}
+    /// // Mise à jour de A: ancienne valeur 1 retournée, A repasse en tête
+    /// // cache = [A, B]
     /// assert_eq!(cache.put("C".to_string(), 4), None);
-    /// // Capacité 2 dépassée -> B (le moins récemment utilisé) est éjecté
-    /// // cache = {A:{index:3, value:3}, C:{index:4, value:4}}
+    /// // Capacité 2 dépassée -> B (en queue) est éjecté
+    /// // cache = [C, A]
     /// assert!(cache.get(&"B".to_string()).is_none());
     /// ```
     fn put(&mut self, key: K, value: V) -> Option<V>;
 
-    /// Récupère un élément complet (valeur et index) dans le cache sans mettre à jour l'ordre LRU.
+    /// Comme [`CacheTrait::put`], mais renvoie en plus l'entrée éjectée pour faire de la place
+    /// à la nouvelle clé, le cas échéant (`None` si la clé existait déjà, ou si le cache
+    /// n'était pas plein). Permet à un appelant qui compose plusieurs caches (par ex.
+    /// [`crate::tiered::TieredCache`]) de réagir à une éviction plutôt que de la perdre
+    /// silencieusement.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use lru_cache::cache::{Cache, CacheTrait};
+    /// let mut cache = Cache::new(1);
+    /// assert_eq!(cache.put_evicted("A".to_string(), 1), (None, None));
+    /// // Capacité 1 dépassée : "A" est éjectée pour faire de la place à "B".
+    /// assert_eq!(
+    ///     cache.put_evicted("B".to_string(), 2),
+    ///     (None, Some(("A".to_string(), 1)))
+    /// );
+    /// ```
+    fn put_evicted(&mut self, key: K, value: V) -> (Option<V>, Option<(K, V)>);
+
+    /// Récupère un élément complet (valeur et voisins) dans le cache sans mettre à jour l'ordre LRU.
     /// Cette méthode est destinée principalement aux tests unitaires.
     ///
     /// # Exemple
@@ -151,7 +224,7 @@ pub trait CacheTrait<K, V> {
     ///     assert_eq!(elt.value, "val");
     /// }
     /// ```
-    fn get_elt(&self, key: &K) -> Option<&Element<V>>;
+    fn get_elt(&self, key: &K) -> Option<&Element<K, V>>;
 }
 
 impl<K, V> CacheTrait<K, V> for Cache<K, V>
@@ -163,60 +236,474 @@ where
         Self {
             elements: HashMap::new(),
             size,
-            max_index: 0,
+            head: None,
+            tail: None,
+            scale: ZeroWeightScale,
+            total_weight: 0,
         }
     }
 
+    // Délègue aux méthodes inhérentes de `Cache<K, V, ZeroWeightScale, H>` (voir plus bas),
+    // génériques sur le hasher `H` : la résolution de méthode privilégie toujours un inhérent
+    // sur un trait, donc `self.put(...)` ici appelle bien cet inhérent plutôt que de se
+    // rappeler lui-même.
     fn put(&mut self, key: K, value: V) -> Option<V> {
-        match self.elements.get_mut(&key) {
-            Some(elt) => {
+        self.put(key, value)
+    }
+
+    fn put_evicted(&mut self, key: K, value: V) -> (Option<V>, Option<(K, V)>) {
+        self.put_evicted(key, value)
+    }
+
+    fn get(&mut self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn get_elt(&self, key: &K) -> Option<&Element<K, V>> {
+        self.get_elt(key)
+    }
+}
+
+impl<K, V> Cache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    /// Crée un cache de capacité `size`, en rejetant une capacité nulle plutôt que de
+    /// produire silencieusement un cache qui ne retiendrait jamais aucun élément inséré
+    /// via `put` (voir [`CacheError::ZeroCapacity`]).
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use lru_cache::cache::{Cache, CacheError};
+    ///
+    /// assert_eq!(
+    ///     Cache::<String, String>::try_new(0).unwrap_err(),
+    ///     CacheError::ZeroCapacity
+    /// );
+    /// assert!(Cache::<String, String>::try_new(3).is_ok());
+    /// ```
+    pub fn try_new(size: usize) -> Result<Self, CacheError> {
+        if size == 0 {
+            return Err(CacheError::ZeroCapacity);
+        }
+        Ok(Self::new(size))
+    }
+}
+
+impl<K, V, H> Cache<K, V, ZeroWeightScale, H>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    H: BuildHasher,
+{
+    /// Crée un cache de capacité `size` utilisant `hasher` au lieu du `RandomState` par défaut
+    /// de `Cache::new`. Utile pour brancher un hasher non cryptographique plus rapide
+    /// (par ex. FxHash/ahash) sur un cache chaud, sans changer le comportement de `put`/`get`.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use lru_cache::cache::Cache;
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let mut cache: Cache<String, String, _, _> = Cache::with_hasher(2, RandomState::new());
+    /// cache.put("A".to_string(), "1".to_string());
+    /// assert_eq!(cache.get(&"A".to_string()), Some(&"1".to_string()));
+    /// ```
+    pub fn with_hasher(size: usize, hasher: H) -> Self {
+        Self {
+            elements: HashMap::with_hasher(hasher),
+            size,
+            head: None,
+            tail: None,
+            scale: ZeroWeightScale,
+            total_weight: 0,
+        }
+    }
+
+    /// Insère ou met à jour `key`/`value`, en éjectant au besoin l'élément le moins récemment
+    /// utilisé. Voir [`CacheTrait::put`], que cette méthode implémente pour tout hasher `H`.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        self.put_evicted(key, value).0
+    }
+
+    /// Comme [`Cache::put`], mais renvoie en plus l'entrée éjectée pour faire de la place,
+    /// le cas échéant. Voir [`CacheTrait::put_evicted`], que cette méthode implémente pour
+    /// tout hasher `H`.
+    pub fn put_evicted(&mut self, key: K, value: V) -> (Option<V>, Option<(K, V)>) {
+        if self.elements.contains_key(&key) {
+            self.unlink(&key);
+            let old_value = {
+                let elt = self.elements.get_mut(&key).unwrap();
                 let old_value = elt.value.clone();
-                elt.value = value.clone();
-                self.max_index += 1;
-                elt.index = self.max_index;
-                return Some(old_value);
+                elt.value = value;
+                old_value
+            };
+            self.push_front(key);
+            return (Some(old_value), None);
+        }
+
+        let mut evicted = None;
+        if self.elements.len() >= self.size {
+            if let Some(tail_key) = self.tail.clone() {
+                self.unlink(&tail_key);
+                if let Some(elt) = self.elements.remove(&tail_key) {
+                    evicted = Some((tail_key, elt.value));
+                }
             }
-            None => {
-                if self.elements.len() >= self.size {
-                    if let Some((oldest_key, _)) =
-                        self.elements.iter().min_by_key(|(_, elt)| elt.index)
-                    {
-                        self.elements.remove(&oldest_key.clone());
-                    }
+        }
+
+        self.elements.insert(
+            key.clone(),
+            Element {
+                value,
+                prev: None,
+                next: None,
+                weight: 0,
+            },
+        );
+        self.push_front(key);
+        (None, evicted)
+    }
+}
+
+impl<K, V, S, H> Cache<K, V, S, H>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    H: BuildHasher,
+{
+    /// Retire la clé de sa position actuelle dans la liste chaînée en patchant ses voisins,
+    /// sans la retirer de la `HashMap`.
+    fn unlink(&mut self, key: &K) {
+        let (prev, next) = match self.elements.get(key) {
+            Some(elt) => (elt.prev.clone(), elt.next.clone()),
+            None => return,
+        };
+
+        match &prev {
+            Some(prev_key) => {
+                if let Some(prev_elt) = self.elements.get_mut(prev_key) {
+                    prev_elt.next = next.clone();
                 }
+            }
+            None => self.head = next.clone(),
+        }
 
-                self.max_index += 1;
-                self.elements.insert(
-                    key,
-                    Element {
-                        index: self.max_index,
-                        value: value,
-                    },
-                );
-                return None;
+        match &next {
+            Some(next_key) => {
+                if let Some(next_elt) = self.elements.get_mut(next_key) {
+                    next_elt.prev = prev.clone();
+                }
             }
+            None => self.tail = prev.clone(),
         }
     }
 
-    fn get(&mut self, key: &K) -> Option<&V> {
-        match self.elements.get_mut(key) {
-            Some(elt) => {
-                self.max_index += 1;
-                elt.index = self.max_index;
-                return Some(&elt.value);
+    /// Place la clé (déjà présente dans `elements`) en tête de liste, c'est-à-dire
+    /// en position de plus récemment utilisée.
+    fn push_front(&mut self, key: K) {
+        let old_head = self.head.clone();
+
+        if let Some(head_key) = &old_head {
+            if let Some(head_elt) = self.elements.get_mut(head_key) {
+                head_elt.prev = Some(key.clone());
             }
-            None => None,
         }
+
+        if let Some(elt) = self.elements.get_mut(&key) {
+            elt.prev = None;
+            elt.next = old_head;
+        }
+
+        if self.tail.is_none() {
+            self.tail = Some(key.clone());
+        }
+        self.head = Some(key);
+    }
+
+    /// Récupère la valeur associée à `key`, en replaçant l'élément en tête de la liste LRU.
+    /// Voir [`CacheTrait::get`], que cette méthode implémente pour tout `S`/hasher `H` —
+    /// y compris un cache construit via [`Cache::with_weight`].
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        if !self.elements.contains_key(key) {
+            return None;
+        }
+
+        self.unlink(key);
+        self.push_front(key.clone());
+        self.elements.get(key).map(|elt| &elt.value)
+    }
+
+    /// Récupère un élément complet sans mettre à jour l'ordre LRU.
+    /// Voir [`CacheTrait::get_elt`], que cette méthode implémente pour tout `S`/hasher `H`.
+    pub fn get_elt(&self, key: &K) -> Option<&Element<K, V>> {
+        self.elements.get(key)
+    }
+
+    /// Lit la valeur associée à `key` sans toucher à l'ordre LRU, contrairement à
+    /// [`Cache::get`]. C'est la version publique, pour tout `V`, de [`Cache::get_elt`].
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use lru_cache::cache::{Cache, CacheTrait};
+    ///
+    /// let mut cache: Cache<String, String> = Cache::new(2);
+    /// cache.put("A".to_string(), "1".to_string());
+    /// cache.put("B".to_string(), "2".to_string());
+    /// assert_eq!(cache.peek(&"A".to_string()), Some(&"1".to_string()));
+    /// // contrairement à `get`, l'ordre LRU n'a pas changé : B reste en tête.
+    /// assert_eq!(cache.head, Some("B".to_string()));
+    /// ```
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.elements.get(key).map(|elt| &elt.value)
+    }
+
+    /// Indique si `key` est présente dans le cache, sans toucher à l'ordre LRU.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use lru_cache::cache::{Cache, CacheTrait};
+    ///
+    /// let mut cache: Cache<String, String> = Cache::new(2);
+    /// cache.put("A".to_string(), "1".to_string());
+    /// assert!(cache.contains(&"A".to_string()));
+    /// assert!(!cache.contains(&"B".to_string()));
+    /// ```
+    pub fn contains(&self, key: &K) -> bool {
+        self.elements.contains_key(key)
     }
 
-    fn get_elt(&self, key: &K) -> Option<&Element<V>> {
-        match self.elements.get(key) {
-            Some(elt) => {
-                return Some(elt);
+    /// Retire `key` du cache et renvoie sa valeur, si elle était présente, en ajustant
+    /// `total_weight` du poids de l'élément retiré (`0` pour un cache à capacité en nombre
+    /// d'éléments, puisque [`CacheTrait::put`] insère toujours avec un poids nul).
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use lru_cache::cache::{Cache, CacheTrait};
+    ///
+    /// let mut cache: Cache<String, String> = Cache::new(2);
+    /// cache.put("A".to_string(), "1".to_string());
+    /// assert_eq!(cache.pop(&"A".to_string()), Some("1".to_string()));
+    /// assert!(!cache.contains(&"A".to_string()));
+    /// assert_eq!(cache.pop(&"A".to_string()), None);
+    /// ```
+    pub fn pop(&mut self, key: &K) -> Option<V> {
+        self.unlink(key);
+        let elt = self.elements.remove(key)?;
+        self.total_weight -= elt.weight;
+        Some(elt.value)
+    }
+
+    /// Crée un cache à capacité pondérée : `size` borne `total_weight`, la somme des poids
+    /// des éléments présents, plutôt que leur nombre — `elements.len()` lui-même n'est pas
+    /// borné. Le poids de chaque valeur est calculé par `scale` (voir [`WeightScale`]).
+    /// Les insertions se font via [`Cache::put_with_weight`].
+    pub fn with_weight(size: usize, scale: S) -> Self
+    where
+        S: WeightScale<V>,
+        H: Default,
+    {
+        Self {
+            elements: HashMap::default(),
+            size,
+            head: None,
+            tail: None,
+            scale,
+            total_weight: 0,
+        }
+    }
+
+    /// Insère une paire clé-valeur en tenant compte de son poids (`self.scale.weight(&value)`).
+    ///
+    /// Pour faire de la place, les entrées les moins récemment utilisées sont évincées une à une
+    /// jusqu'à ce que le nouvel élément tienne (`total_weight + poids <= size`), y compris lors de
+    /// la mise à jour d'une clé déjà présente dont la valeur s'alourdit. Si le poids de l'élément à lui
+    /// seul dépasse `size`, aucune éviction n'est tentée et `CacheError::TooHeavy` est renvoyée.
+    pub fn put_with_weight(&mut self, key: K, value: V) -> Result<Option<V>, CacheError>
+    where
+        S: WeightScale<V>,
+    {
+        let weight = self.scale.weight(&value);
+        if weight > self.size {
+            return Err(CacheError::TooHeavy);
+        }
+
+        if self.elements.contains_key(&key) {
+            let old_weight = self.elements.get(&key).unwrap().weight;
+            self.unlink(&key);
+            self.total_weight -= old_weight;
+
+            while self.total_weight + weight > self.size {
+                let Some(tail_key) = self.tail.clone() else {
+                    break;
+                };
+                self.unlink(&tail_key);
+                if let Some(evicted) = self.elements.remove(&tail_key) {
+                    self.total_weight -= evicted.weight;
+                }
             }
-            None => None,
+
+            let old_value = {
+                let elt = self.elements.get_mut(&key).unwrap();
+                let old_value = elt.value.clone();
+                elt.value = value;
+                elt.weight = weight;
+                old_value
+            };
+
+            self.total_weight += weight;
+            self.push_front(key);
+            return Ok(Some(old_value));
         }
+
+        while self.total_weight + weight > self.size {
+            let Some(tail_key) = self.tail.clone() else {
+                break;
+            };
+            self.unlink(&tail_key);
+            if let Some(evicted) = self.elements.remove(&tail_key) {
+                self.total_weight -= evicted.weight;
+            }
+        }
+
+        self.elements.insert(
+            key.clone(),
+            Element {
+                value,
+                prev: None,
+                next: None,
+                weight,
+            },
+        );
+        self.total_weight += weight;
+        self.push_front(key);
+        Ok(None)
     }
+
+    /// Parcourt les entrées du cache sans modifier l'ordre LRU, du plus récemment utilisé
+    /// (`head`) au moins récemment utilisé (`tail`).
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use lru_cache::cache::{Cache, CacheTrait};
+    ///
+    /// let mut cache: Cache<String, String> = Cache::new(3);
+    /// cache.put("A".to_string(), "1".to_string());
+    /// cache.put("B".to_string(), "2".to_string());
+    /// // cache = [B, A]
+    ///
+    /// let entries: Vec<(&String, &String)> = cache.iter().collect();
+    /// assert_eq!(
+    ///     entries,
+    ///     vec![(&"B".to_string(), &"2".to_string()), (&"A".to_string(), &"1".to_string())]
+    /// );
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V, S, H> {
+        Iter {
+            cache: self,
+            current: self.head.clone(),
+        }
+    }
+
+    /// Parcourt les clés du cache du plus récemment utilisé au moins récemment utilisé,
+    /// sans modifier l'ordre LRU. Voir [`Cache::iter`].
+    pub fn keys(&self) -> Keys<'_, K, V, S, H> {
+        Keys(self.iter())
+    }
+
+    /// Parcourt les valeurs du cache du plus récemment utilisé au moins récemment utilisé,
+    /// sans modifier l'ordre LRU. Voir [`Cache::iter`].
+    pub fn values(&self) -> Values<'_, K, V, S, H> {
+        Values(self.iter())
+    }
+}
+
+/// Itérateur sur les entrées d'un [`Cache`], du plus récemment utilisé au moins récemment
+/// utilisé. Créé par [`Cache::iter`].
+pub struct Iter<'a, K, V, S, H> {
+    cache: &'a Cache<K, V, S, H>,
+    current: Option<K>,
+}
+
+impl<'a, K, V, S, H> Iterator for Iter<'a, K, V, S, H>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    H: BuildHasher,
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.current.take()?;
+        let (stored_key, elt) = self.cache.elements.get_key_value(&key)?;
+        self.current = elt.next.clone();
+        Some((stored_key, &elt.value))
+    }
+}
+
+impl<K, V, S, H> std::iter::FusedIterator for Iter<'_, K, V, S, H>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    H: BuildHasher,
+{
+}
+
+/// Itérateur sur les clés d'un [`Cache`], du plus récemment utilisé au moins récemment
+/// utilisé. Créé par [`Cache::keys`].
+pub struct Keys<'a, K, V, S, H>(Iter<'a, K, V, S, H>);
+
+impl<'a, K, V, S, H> Iterator for Keys<'a, K, V, S, H>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    H: BuildHasher,
+{
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(key, _)| key)
+    }
+}
+
+impl<K, V, S, H> std::iter::FusedIterator for Keys<'_, K, V, S, H>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    H: BuildHasher,
+{
+}
+
+/// Itérateur sur les valeurs d'un [`Cache`], du plus récemment utilisé au moins récemment
+/// utilisé. Créé par [`Cache::values`].
+pub struct Values<'a, K, V, S, H>(Iter<'a, K, V, S, H>);
+
+impl<'a, K, V, S, H> Iterator for Values<'a, K, V, S, H>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    H: BuildHasher,
+{
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, value)| value)
+    }
+}
+
+impl<K, V, S, H> std::iter::FusedIterator for Values<'_, K, V, S, H>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    H: BuildHasher,
+{
 }
 
 #[cfg(test)]
@@ -229,10 +716,25 @@ mod tests {
         }
     }
 
-    fn test_elt_index(cache: &Cache<String, String>, key: String, value: usize) {
-        if let Some(elt) = cache.get_elt(&key.to_string()) {
-            assert_eq!(elt.index, value);
+    /// Vérifie que la liste chaînée, parcourue de `head` à `tail`, correspond exactement
+    /// à `expected_mru_to_lru` (du plus récemment utilisé au moins récemment utilisé).
+    fn assert_lru_order(cache: &Cache<String, String>, expected_mru_to_lru: &[&str]) {
+        let mut forward = Vec::new();
+        let mut current = cache.head.clone();
+        while let Some(key) = current {
+            forward.push(key.clone());
+            current = cache.elements.get(&key).and_then(|elt| elt.next.clone());
+        }
+        assert_eq!(forward, expected_mru_to_lru);
+
+        let mut backward = Vec::new();
+        let mut current = cache.tail.clone();
+        while let Some(key) = current {
+            backward.push(key.clone());
+            current = cache.elements.get(&key).and_then(|elt| elt.prev.clone());
         }
+        backward.reverse();
+        assert_eq!(backward, expected_mru_to_lru);
     }
 
     #[test]
@@ -271,7 +773,8 @@ mod tests {
         let cache: Cache<String, String> = Cache::new(3);
         assert!(cache.elements.is_empty());
         assert_eq!(cache.size, 3);
-        assert_eq!(cache.max_index, 0);
+        assert_eq!(cache.head, None);
+        assert_eq!(cache.tail, None);
     }
 
     #[test]
@@ -319,14 +822,12 @@ mod tests {
 
         test_elt_value(&cache, "A".to_string(), "value_a".to_string());
         test_elt_value(&cache, "B".to_string(), "value_b".to_string());
-        test_elt_index(&cache, "A".to_string(), 1);
-        test_elt_index(&cache, "B".to_string(), 2);
+        assert_lru_order(&cache, &["B", "A"]);
 
         assert_eq!(cache.get(&"A".to_string()), Some(&"value_a".to_string()));
         assert_eq!(cache.get(&"B".to_string()), Some(&"value_b".to_string()));
 
-        test_elt_index(&cache, "A".to_string(), 3);
-        test_elt_index(&cache, "B".to_string(), 4);
+        assert_lru_order(&cache, &["B", "A"]);
     }
 
     #[test]
@@ -341,7 +842,7 @@ mod tests {
         );
 
         test_elt_value(&cache, "A".to_string(), "value_A".to_string());
-        test_elt_index(&cache, "A".to_string(), 3);
+        assert_lru_order(&cache, &["A", "B"]);
 
         assert_eq!(cache.elements.len(), 2)
     }
@@ -389,21 +890,276 @@ mod tests {
         let _ = cache.put("B".to_string(), "value_b".to_string());
         let _ = cache.put("C".to_string(), "value_c".to_string());
 
-        test_elt_index(&cache, "A".to_string(), 1);
-        test_elt_index(&cache, "B".to_string(), 2);
-        test_elt_index(&cache, "C".to_string(), 3);
+        assert_lru_order(&cache, &["C", "B", "A"]);
 
         let _ = cache.get(&"B".to_string());
         let _ = cache.get(&"B".to_string());
         let _ = cache.get(&"B".to_string());
         let _ = cache.get(&"B".to_string());
-        test_elt_index(&cache, "A".to_string(), 1);
-        test_elt_index(&cache, "B".to_string(), 7);
-        test_elt_index(&cache, "C".to_string(), 3);
+        assert_lru_order(&cache, &["B", "C", "A"]);
+
+        let _ = cache.get(&"A".to_string());
+        assert_lru_order(&cache, &["A", "B", "C"]);
+    }
+
+    #[test]
+    fn test_ordre_lru_stable_apres_churn_prolonge() {
+        // Régression pour la liste chaînée intrusive : contrairement à un schéma à base
+        // d'un compteur `index` global monotone, l'ordre LRU ne dépend ici que des voisins
+        // `prev`/`next` de chaque `Element`, donc reste correct après un grand nombre
+        // d'insertions/évictions sans jamais risquer de débordement de compteur.
+        let mut cache: Cache<usize, usize> = Cache::new(3);
+
+        for i in 0..10_000 {
+            cache.put(i, i);
+            assert_eq!(cache.head, Some(i));
+        }
+
+        let mut forward = Vec::new();
+        let mut current = cache.head;
+        while let Some(key) = current {
+            forward.push(key);
+            current = cache.elements.get(&key).and_then(|elt| elt.next);
+        }
+        assert_eq!(forward, vec![9999, 9998, 9997]);
+    }
+
+    struct LenWeightScale;
+
+    impl WeightScale<String> for LenWeightScale {
+        fn weight(&self, value: &String) -> usize {
+            value.len()
+        }
+    }
+
+    #[test]
+    fn test_put_with_weight_evicts_until_it_fits() {
+        let mut cache: Cache<String, String, LenWeightScale> =
+            Cache::with_weight(10, LenWeightScale);
+
+        assert_eq!(
+            cache.put_with_weight("A".to_string(), "123".to_string()),
+            Ok(None)
+        );
+        assert_eq!(
+            cache.put_with_weight("B".to_string(), "1234".to_string()),
+            Ok(None)
+        );
+        assert!(cache.contains(&"A".to_string()));
+        assert!(cache.contains(&"B".to_string()));
+
+        // A et B occupent déjà assez de capacité pour que C ne tienne pas sans éviction :
+        // A (le moins récemment utilisé) est éjecté pour faire de la place.
+        assert_eq!(
+            cache.put_with_weight("C".to_string(), "5678".to_string()),
+            Ok(None)
+        );
+        assert!(!cache.contains(&"A".to_string()));
+        assert!(cache.contains(&"B".to_string()));
+        assert!(cache.contains(&"C".to_string()));
+        assert_eq!(cache.get(&"B".to_string()), Some(&"1234".to_string()));
+        assert_eq!(cache.get(&"C".to_string()), Some(&"5678".to_string()));
+    }
+
+    #[test]
+    fn test_put_with_weight_elements_len_non_borne() {
+        // L'invariant porte sur `total_weight <= size`, pas sur `elements.len()` : des valeurs
+        // de poids nul peuvent donc s'accumuler au-delà de `size` éléments.
+        struct AlwaysZero;
+        impl WeightScale<String> for AlwaysZero {
+            fn weight(&self, _value: &String) -> usize {
+                0
+            }
+        }
+
+        let mut cache: Cache<String, String, AlwaysZero> = Cache::with_weight(2, AlwaysZero);
+        for i in 0..10 {
+            cache
+                .put_with_weight(format!("K{i}"), "v".to_string())
+                .unwrap();
+        }
 
+        assert_eq!(cache.elements.len(), 10);
+        assert_eq!(cache.total_weight, 0);
+    }
+
+    #[test]
+    fn test_put_with_weight_too_heavy() {
+        let mut cache: Cache<String, String, LenWeightScale> =
+            Cache::with_weight(5, LenWeightScale);
+
+        assert_eq!(
+            cache.put_with_weight("A".to_string(), "0123456789".to_string()),
+            Err(CacheError::TooHeavy)
+        );
+        assert!(!cache.contains(&"A".to_string()));
+    }
+
+    #[test]
+    fn test_get_peek_contains_pop_sur_cache_pondere() {
+        // `get`/`peek`/`contains`/`pop` doivent fonctionner sur un cache construit avec une
+        // échelle de poids non triviale, pas seulement sur le `Cache<K, V>` par défaut.
+        let mut cache: Cache<String, String, LenWeightScale> =
+            Cache::with_weight(10, LenWeightScale);
+
+        cache
+            .put_with_weight("A".to_string(), "123".to_string())
+            .unwrap();
+        cache
+            .put_with_weight("B".to_string(), "1234".to_string())
+            .unwrap();
+
+        assert_eq!(cache.peek(&"A".to_string()), Some(&"123".to_string()));
+        assert!(cache.contains(&"A".to_string()));
+        assert_eq!(cache.get(&"A".to_string()), Some(&"123".to_string()));
+        // `get` a replacé A en tête : cache = [A, B]
+        assert_eq!(cache.head, Some("A".to_string()));
+
+        assert_eq!(cache.pop(&"A".to_string()), Some("123".to_string()));
+        assert!(!cache.contains(&"A".to_string()));
+        assert_eq!(cache.total_weight, 4);
+        assert_eq!(cache.pop(&"A".to_string()), None);
+    }
+
+    #[test]
+    fn test_peek_ne_modifie_pas_ordre_lru() {
+        let mut cache: Cache<String, String> = Cache::new(2);
+        cache.put("A".to_string(), "value_a".to_string());
+        cache.put("B".to_string(), "value_b".to_string());
+        // cache = [B, A]
+
+        assert_eq!(cache.peek(&"A".to_string()), Some(&"value_a".to_string()));
+        assert_lru_order(&cache, &["B", "A"]);
+        assert_eq!(cache.peek(&"X".to_string()), None);
+    }
+
+    #[test]
+    fn test_contains() {
+        let mut cache: Cache<String, String> = Cache::new(2);
+        cache.put("A".to_string(), "value_a".to_string());
+
+        assert!(cache.contains(&"A".to_string()));
+        assert!(!cache.contains(&"B".to_string()));
+    }
+
+    #[test]
+    fn test_pop_retire_et_renvoie_la_valeur() {
+        let mut cache: Cache<String, String> = Cache::new(2);
+        cache.put("A".to_string(), "value_a".to_string());
+        cache.put("B".to_string(), "value_b".to_string());
+
+        assert_eq!(cache.pop(&"A".to_string()), Some("value_a".to_string()));
+        assert!(!cache.contains(&"A".to_string()));
+        assert_eq!(cache.pop(&"A".to_string()), None);
+        assert_lru_order(&cache, &["B"]);
+    }
+
+    #[test]
+    fn test_iter_ordre_lru() {
+        let mut cache: Cache<String, String> = Cache::new(3);
+        cache.put("A".to_string(), "value_a".to_string());
+        cache.put("B".to_string(), "value_b".to_string());
+        cache.put("C".to_string(), "value_c".to_string());
         let _ = cache.get(&"A".to_string());
-        test_elt_index(&cache, "A".to_string(), 8);
-        test_elt_index(&cache, "B".to_string(), 7);
-        test_elt_index(&cache, "C".to_string(), 3);
+        // cache = [A, C, B]
+
+        let entries: Vec<(&String, &String)> = cache.iter().collect();
+        assert_eq!(
+            entries,
+            vec![
+                (&"A".to_string(), &"value_a".to_string()),
+                (&"C".to_string(), &"value_c".to_string()),
+                (&"B".to_string(), &"value_b".to_string()),
+            ]
+        );
+
+        let keys: Vec<&String> = cache.keys().collect();
+        assert_eq!(keys, vec!["A", "C", "B"]);
+
+        let values: Vec<&String> = cache.values().collect();
+        assert_eq!(values, vec!["value_a", "value_c", "value_b"]);
+    }
+
+    #[test]
+    fn test_iter_ne_modifie_pas_ordre_lru() {
+        let mut cache: Cache<String, String> = Cache::new(2);
+        cache.put("A".to_string(), "value_a".to_string());
+        cache.put("B".to_string(), "value_b".to_string());
+
+        let _: Vec<_> = cache.iter().collect();
+        assert_lru_order(&cache, &["B", "A"]);
+    }
+
+    #[test]
+    fn test_iter_cache_vide() {
+        let cache: Cache<String, String> = Cache::new(2);
+        assert_eq!(cache.iter().next(), None);
+    }
+
+    #[test]
+    fn test_iter_est_fused() {
+        let mut cache: Cache<String, String> = Cache::new(2);
+        cache.put("A".to_string(), "value_a".to_string());
+
+        let mut iter = cache.iter();
+        assert!(iter.next().is_some());
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    /// `BuildHasher` minimaliste (non utilisable en production) servant uniquement à vérifier
+    /// que `Cache::with_hasher` branche bien le hasher fourni dans la `HashMap` sous-jacente.
+    #[derive(Default)]
+    struct ConstantHasher;
+
+    impl std::hash::BuildHasher for ConstantHasher {
+        type Hasher = std::collections::hash_map::DefaultHasher;
+
+        fn build_hasher(&self) -> Self::Hasher {
+            std::collections::hash_map::DefaultHasher::new()
+        }
+    }
+
+    #[test]
+    fn test_with_hasher_get_put_get_elt() {
+        let mut cache: Cache<String, String, ZeroWeightScale, ConstantHasher> =
+            Cache::with_hasher(2, ConstantHasher);
+
+        assert_eq!(cache.put("A".to_string(), "value_a".to_string()), None);
+        assert_eq!(cache.put("B".to_string(), "value_b".to_string()), None);
+        assert_eq!(cache.get(&"A".to_string()), Some(&"value_a".to_string()));
+
+        cache.put("C".to_string(), "value_c".to_string());
+        // Capacité 2 dépassée : B (en queue après le get de A) est éjecté.
+        assert!(cache.get_elt(&"B".to_string()).is_none());
+        assert!(cache.get_elt(&"A".to_string()).is_some());
+        assert!(cache.get_elt(&"C".to_string()).is_some());
+    }
+
+    #[test]
+    fn test_try_new_rejette_capacite_nulle() {
+        let result: Result<Cache<String, String>, CacheError> = Cache::try_new(0);
+        assert_eq!(result.unwrap_err(), CacheError::ZeroCapacity);
+    }
+
+    #[test]
+    fn test_try_new_accepte_capacite_non_nulle() {
+        let cache: Cache<String, String> = Cache::try_new(3).unwrap();
+        assert_eq!(cache.size, 3);
+        assert!(cache.elements.is_empty());
+    }
+
+    #[test]
+    fn test_put_unaffected_by_weight_mode() {
+        // Le constructeur `new`/`put` historique reste borné par le nombre d'éléments,
+        // quel que soit le contenu, via l'échelle de poids nulle par défaut.
+        let mut cache: Cache<String, String> = Cache::new(2);
+        cache.put("A".to_string(), "une très longue valeur".to_string());
+        cache.put("B".to_string(), "une autre très longue valeur".to_string());
+        cache.put("C".to_string(), "encore une autre valeur".to_string());
+
+        assert!(cache.get_elt(&"A".to_string()).is_none());
+        assert!(cache.get_elt(&"B".to_string()).is_some());
+        assert!(cache.get_elt(&"C".to_string()).is_some());
     }
 }
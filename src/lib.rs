@@ -9,7 +9,11 @@
 //!
 //! ## Performances
 //! - get : O(1)
-//! - put : O(n) pour trouver l'élément le moins récemment utilisé
+//! - put : O(1), l'ordre LRU étant maintenu par une liste doublement chaînée
+//!   filée à travers la `HashMap` (pas de parcours pour trouver l'élément
+//!   le moins récemment utilisé)
 
 pub mod cache;
 pub mod persistence;
+pub mod tiered;
+pub mod two_queue;
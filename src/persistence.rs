@@ -3,12 +3,47 @@
 //! Ce module définit un trait `Persistence` permettant de sauvegarder et lire
 //! le contenu d'un objet `Cache` dans un fichier texte. L'implémentation `FilePersistence`
 //! lit et écrit chaque paire clé-valeur sous la forme `clé:valeur` par ligne,
-//! en préservant l'ordre LRU à l'aide de l'index interne à l'objet `Cache` définit dans `cache.rs`.
-
-use crate::cache::{Cache, CacheTrait, Element};
-use std::fs::{File, read_to_string, write};
+//! en préservant l'ordre LRU en parcourant la liste chaînée de l'objet `Cache`
+//! (définie dans `cache.rs`) de `tail` (le plus ancien) à `head` (le plus récent).
+//!
+//! `write_file` écrit de façon atomique : le contenu est d'abord écrit dans un fichier
+//! temporaire du même répertoire, synchronisé sur disque (`fsync`), puis renommé par-dessus
+//! la destination. Un lecteur concurrent ou un crash en plein milieu de l'écriture voit donc
+//! toujours soit l'ancien fichier, soit le nouveau complet, jamais un contenu tronqué.
+//!
+//! Le format `clé:valeur` de `FilePersistence` repose sur `ToString`/`FromStr` et casse dès
+//! qu'une clé contient `:`, ou qu'une clé ou une valeur contient un saut de ligne. `SerdePersistence<F>` propose une
+//! alternative structurée (JSON via [`Json`], MessagePack via [`MsgPack`]) reposant sur
+//! `serde::Serialize`/`Deserialize`, qui encode explicitement l'ordre LRU au lieu de le déduire
+//! de la position des lignes.
+
+use crate::cache::{Cache, CacheTrait};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, read_to_string};
 use std::hash::Hash;
+use std::io::{self, Write};
+use std::marker::PhantomData;
+use std::path::Path;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Compteur utilisé pour donner un nom unique au fichier temporaire de `write_file`,
+/// afin que deux écritures concurrentes ne se marchent pas dessus.
+static TMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Crée le répertoire parent de `file_path` s'il n'existe pas déjà.
+///
+/// Optimisé pour le cas courant où le répertoire existe déjà : on vérifie d'abord
+/// son existence avant de tenter une création.
+fn ensure_directory(file_path: &str) -> io::Result<()> {
+    match Path::new(file_path).parent() {
+        Some(parent) if !parent.as_os_str().is_empty() && !parent.exists() => {
+            fs::create_dir_all(parent)
+        }
+        _ => Ok(()),
+    }
+}
 
 /// Trait pour gérer la persistance d'un cache LRU dans un fichier texte.
 ///
@@ -20,7 +55,10 @@ pub trait Persistence<K, V> {
     ///
     /// Les paires clé-valeur sont lues ligne par ligne au format `clé:valeur`.
     /// Seules les `size` dernières lignes (les plus récentes) sont utilisées si le fichier contient plus d'entrées.
-    /// Si le fichier n'existe pas, il est créé et un cache vide est retourné.
+    /// Si le fichier n'existe pas, son répertoire parent est créé si besoin, le fichier est créé vide,
+    /// et un cache vide est retourné. Les erreurs d'E/S (autres que « fichier absent ») sont renvoyées.
+    /// Une `size` nulle est rejetée (`io::ErrorKind::InvalidInput`) plutôt que de produire un
+    /// cache qui ne retiendrait jamais aucune ligne lue (voir [`Cache::try_new`]).
     ///
     /// # Exemple si nombre de ligne est égal à la taille du cache
     ///
@@ -35,8 +73,8 @@ pub trait Persistence<K, V> {
     /// let file_path = "fichiers/cache.txt";
     /// let _ = write(file_path, "A:valeur1\nB:valeur2\n");
     ///
-    /// let mut cache: Cache<String, String> = FilePersistence::read_file(2, file_path);
-    /// // cache = {cache = {A:{index:1, value:valeur1}, B:{index:2, value:valeur2}}}
+    /// let mut cache: Cache<String, String> = FilePersistence::read_file(2, file_path).unwrap();
+    /// // cache = [B, A] (B le plus récent, car lu en dernier)
     /// assert_eq!(cache.get(&"A".to_string()), Some(&"valeur1".to_string()));
     /// assert_eq!(cache.get(&"B".to_string()), Some(&"valeur2".to_string()));
     /// let _ = remove_file(file_path);
@@ -54,8 +92,8 @@ pub trait Persistence<K, V> {
     /// // B:valeur2
     /// let file_path = "fichiers/cache.txt";
     /// let _ = write(file_path, "A:valeur1\nB:valeur2\n");
-    /// let mut cache: Cache<String, String> = FilePersistence::read_file(3, file_path);
-    /// // cache = {cache = {A:{index:1, value:valeur1}, B:{index:2, value:valeur2}}
+    /// let mut cache: Cache<String, String> = FilePersistence::read_file(3, file_path).unwrap();
+    /// // cache = [B, A]
     /// assert_eq!(cache.get(&"A".to_string()), Some(&"valeur1".to_string()));
     /// assert_eq!(cache.get(&"B".to_string()), Some(&"valeur2".to_string()));
     /// let _ = remove_file(file_path);
@@ -75,22 +113,25 @@ pub trait Persistence<K, V> {
     /// let file_path = "fichiers/cache.txt";
     /// let _ = write(file_path, "A:valeur1\nB:valeur2\nC:valeur3\n");
     ///
-    /// let mut cache: Cache<String, String> = FilePersistence::read_file(2, file_path);
-    /// // cache = {cache = {B:{index:1, value:valeur1}, C:{index:2, value:valeur2}}
+    /// let mut cache: Cache<String, String> = FilePersistence::read_file(2, file_path).unwrap();
+    /// // cache = [C, B]
     /// assert_eq!(cache.get(&"A".to_string()), None);
     /// assert_eq!(cache.get(&"B".to_string()), Some(&"valeur2".to_string()));
     /// assert_eq!(cache.get(&"C".to_string()), Some(&"valeur3".to_string()));
     /// let _ = remove_file(file_path);
     /// ```
-    fn read_file(size: usize, file_path: &str) -> Cache<K, V>
+    fn read_file(size: usize, file_path: &str) -> io::Result<Cache<K, V>>
     where
         K: Eq + Hash + Clone + FromStr,
         V: Clone + FromStr;
 
-    /// Écrit le contenu du cache dans un fichier.
+    /// Écrit le contenu du cache dans un fichier, de façon atomique.
     ///
-    /// Les éléments sont triés par ordre d'index croissant (du plus ancien au plus récent)
+    /// Les éléments sont parcourus de `tail` (le plus ancien) à `head` (le plus récent)
     /// pour préserver l'ordre LRU. Chaque ligne du fichier aura la forme `clé:valeur`.
+    /// Le contenu est écrit dans un fichier temporaire du même répertoire (créé si besoin)
+    /// puis renommé par-dessus `file_path`, pour qu'un lecteur concurrent ne voie jamais
+    /// un fichier tronqué.
     ///
     /// # Exemple
     ///
@@ -103,15 +144,15 @@ pub trait Persistence<K, V> {
     /// let mut cache: Cache<String, String> = Cache::new(2);
     /// cache.put("A".to_string(), "1".to_string());
     /// cache.put("B".to_string(), "2".to_string());
-    /// FilePersistence::write_file(&cache, file_path);
+    /// FilePersistence::write_file(&cache, file_path).unwrap();
     /// // Le fichier "cache.txt" contiendra:
     /// // A:1
     /// // B:2
     /// let _ = remove_file(file_path);
     /// ```
-    fn write_file(cache: &Cache<K, V>, file_path: &str)
+    fn write_file(cache: &Cache<K, V>, file_path: &str) -> io::Result<()>
     where
-        K: ToString + Clone,
+        K: Eq + Hash + ToString + Clone,
         V: ToString + Clone;
 }
 
@@ -123,11 +164,18 @@ pub trait Persistence<K, V> {
 pub struct FilePersistence;
 
 impl<K, V> Persistence<K, V> for FilePersistence {
-    fn read_file(size: usize, file_path: &str) -> Cache<K, V>
+    fn read_file(size: usize, file_path: &str) -> io::Result<Cache<K, V>>
     where
         K: Eq + Hash + Clone + FromStr,
         V: Clone + FromStr,
     {
+        if size == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "la capacité du cache ne peut pas être nulle",
+            ));
+        }
+
         let mut cache = Cache::new(size);
 
         match read_to_string(file_path) {
@@ -146,34 +194,198 @@ impl<K, V> Persistence<K, V> for FilePersistence {
                     }
                 }
             }
-            Err(_) => {
-                let _ = File::create(file_path);
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                ensure_directory(file_path)?;
+                File::create(file_path)?;
             }
+            Err(err) => return Err(err),
         }
 
-        cache
+        Ok(cache)
     }
 
-    fn write_file(cache: &Cache<K, V>, file_path: &str)
+    fn write_file(cache: &Cache<K, V>, file_path: &str) -> io::Result<()>
     where
-        K: ToString + Clone,
+        K: Eq + Hash + ToString + Clone,
         V: ToString + Clone,
     {
-        let mut elts: Vec<(K, Element<V>)> = cache
-            .elements
-            .iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect();
+        ensure_directory(file_path)?;
+
+        let mut lines = Vec::new();
+        let mut current = cache.tail.clone();
+
+        while let Some(key) = current {
+            match cache.elements.get(&key) {
+                Some(elt) => {
+                    lines.push(format!("{}:{}", key.to_string(), elt.value.to_string()));
+                    current = elt.prev.clone();
+                }
+                None => break,
+            }
+        }
+
+        let content = lines.join("\n");
+
+        let tmp_path = format!(
+            "{}.tmp-{}-{}",
+            file_path,
+            std::process::id(),
+            TMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+
+        let mut tmp_file = File::create(&tmp_path)?;
+        tmp_file.write_all(content.as_bytes())?;
+        tmp_file.sync_all()?;
+        drop(tmp_file);
+
+        fs::rename(&tmp_path, file_path)?;
+
+        Ok(())
+    }
+}
+
+/// Document structuré persistant le contenu d'un cache : la capacité, et les entrées
+/// ordonnées du plus ancien (index `0`) au plus récent, pour restaurer l'ordre LRU sans
+/// dépendre de la position des lignes dans le fichier.
+#[derive(Serialize, Deserialize)]
+struct CacheDocument<K, V> {
+    size: usize,
+    entries: Vec<(K, V)>,
+}
+
+/// Format d'encodage utilisé par [`SerdePersistence`] pour sérialiser un [`CacheDocument`].
+pub trait Format {
+    /// Sérialise une valeur en octets.
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, String>;
+    /// Désérialise des octets en valeur.
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, String>;
+}
+
+/// Format JSON (`serde_json`), lisible par un humain et facile à déboguer.
+pub struct Json;
+
+impl Format for Json {
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, String> {
+        serde_json::to_vec(value).map_err(|err| err.to_string())
+    }
 
-        elts.sort_by_key(|(_, elt)| elt.index);
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, String> {
+        serde_json::from_slice(bytes).map_err(|err| err.to_string())
+    }
+}
 
-        let content = elts
-            .iter()
-            .map(|(k, e)| format!("{}:{}", k.to_string(), e.value.to_string()))
-            .collect::<Vec<_>>()
-            .join("\n");
+/// Format MessagePack (`rmp_serde`), binaire et compact.
+pub struct MsgPack;
+
+impl Format for MsgPack {
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, String> {
+        rmp_serde::to_vec(value).map_err(|err| err.to_string())
+    }
 
-        let _ = write(file_path, content);
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, String> {
+        rmp_serde::from_slice(bytes).map_err(|err| err.to_string())
+    }
+}
+
+/// Persistance d'un cache dans un document structuré (voir [`Format`]) plutôt que dans le
+/// format texte `clé:valeur` de `FilePersistence`. Fonctionne avec des clés/valeurs
+/// arbitraires via `serde`, sans ambiguïté de délimiteur, et restaure l'ordre LRU depuis
+/// `CacheDocument::entries` plutôt que depuis la position des lignes.
+pub struct SerdePersistence<F> {
+    _format: PhantomData<F>,
+}
+
+impl<F> SerdePersistence<F>
+where
+    F: Format,
+{
+    /// Lit le contenu d'un cache depuis un document encodé par `F`.
+    ///
+    /// Si le fichier n'existe pas, son répertoire parent est créé si besoin, le fichier est
+    /// créé vide, et un cache vide est retourné.
+    pub fn read_file<K, V>(size: usize, file_path: &str) -> io::Result<Cache<K, V>>
+    where
+        K: Eq + Hash + Clone + DeserializeOwned,
+        V: Clone + DeserializeOwned,
+    {
+        if size == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "la capacité du cache ne peut pas être nulle",
+            ));
+        }
+
+        let bytes = match fs::read(file_path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                ensure_directory(file_path)?;
+                File::create(file_path)?;
+                return Ok(Cache::new(size));
+            }
+            Err(err) => return Err(err),
+        };
+
+        let document: CacheDocument<K, V> =
+            F::decode(&bytes).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        let mut entries = document.entries;
+        let n = entries.len();
+        if n > size {
+            entries = entries.split_off(n - size);
+        }
+
+        let mut cache = Cache::new(size);
+        for (key, value) in entries {
+            cache.put(key, value);
+        }
+
+        Ok(cache)
+    }
+
+    /// Écrit le contenu du cache dans un document encodé par `F`, de façon atomique
+    /// (fichier temporaire du même répertoire puis `rename`), comme `FilePersistence::write_file`.
+    pub fn write_file<K, V>(cache: &Cache<K, V>, file_path: &str) -> io::Result<()>
+    where
+        K: Eq + Hash + Clone + Serialize,
+        V: Clone + Serialize,
+    {
+        ensure_directory(file_path)?;
+
+        let mut entries = Vec::new();
+        let mut current = cache.tail.clone();
+
+        while let Some(key) = current {
+            match cache.elements.get(&key) {
+                Some(elt) => {
+                    entries.push((key.clone(), elt.value.clone()));
+                    current = elt.prev.clone();
+                }
+                None => break,
+            }
+        }
+
+        let document = CacheDocument {
+            size: cache.size,
+            entries,
+        };
+        let bytes =
+            F::encode(&document).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        let tmp_path = format!(
+            "{}.tmp-{}-{}",
+            file_path,
+            std::process::id(),
+            TMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+
+        let mut tmp_file = File::create(&tmp_path)?;
+        tmp_file.write_all(&bytes)?;
+        tmp_file.sync_all()?;
+        drop(tmp_file);
+
+        fs::rename(&tmp_path, file_path)?;
+
+        Ok(())
     }
 }
 
@@ -181,7 +393,7 @@ impl<K, V> Persistence<K, V> for FilePersistence {
 mod tests {
     use super::*;
     use crate::cache::{Cache, CacheTrait};
-    use std::fs::{read_to_string, remove_file};
+    use std::fs::{read_to_string, remove_file, write};
 
     fn cleanup(path: &str) {
         let _ = remove_file(path);
@@ -193,10 +405,16 @@ mod tests {
         }
     }
 
-    fn test_elt_index(cache: &Cache<String, String>, key: String, value: usize) {
-        if let Some(elt) = cache.get_elt(&key.to_string()) {
-            assert_eq!(elt.index, value);
+    /// Vérifie que la liste chaînée, parcourue de `head` à `tail`, correspond exactement
+    /// à `expected_mru_to_lru` (du plus récemment utilisé au moins récemment utilisé).
+    fn assert_lru_order(cache: &Cache<String, String>, expected_mru_to_lru: &[&str]) {
+        let mut order = Vec::new();
+        let mut current = cache.head.clone();
+        while let Some(key) = current {
+            order.push(key.clone());
+            current = cache.elements.get(&key).and_then(|elt| elt.next.clone());
         }
+        assert_eq!(order, expected_mru_to_lru);
     }
 
     #[test]
@@ -204,7 +422,7 @@ mod tests {
         let file_path = "fichiers/test_pas_de_fichier.txt";
         cleanup(file_path);
 
-        let cache: Cache<String, String> = FilePersistence::read_file(3, file_path);
+        let cache: Cache<String, String> = FilePersistence::read_file(3, file_path).unwrap();
 
         assert_eq!(cache.elements.len(), 0);
         assert_eq!(cache.size, 3);
@@ -220,12 +438,13 @@ mod tests {
 
         let cache_write: Cache<String, String> = Cache::new(3);
 
-        FilePersistence::write_file(&cache_write, file_path);
-        let read_cache: Cache<String, String> = FilePersistence::read_file(2, file_path);
+        FilePersistence::write_file(&cache_write, file_path).unwrap();
+        let read_cache: Cache<String, String> = FilePersistence::read_file(2, file_path).unwrap();
 
         assert_eq!(read_cache.elements.len(), 0);
         assert_eq!(read_cache.size, 2);
-        assert_eq!(read_cache.max_index, 0);
+        assert_eq!(read_cache.head, None);
+        assert_eq!(read_cache.tail, None);
 
         cleanup(file_path);
     }
@@ -237,7 +456,7 @@ mod tests {
 
         let _ = write(file_path, "A:value_a\nB:value_b\n");
 
-        let cache: Cache<String, String> = FilePersistence::read_file(3, file_path);
+        let cache: Cache<String, String> = FilePersistence::read_file(3, file_path).unwrap();
 
         test_elt_value(&cache, "A".to_string(), "value_a".to_string());
         test_elt_value(&cache, "B".to_string(), "value_b".to_string());
@@ -252,7 +471,7 @@ mod tests {
 
         let _ = write(file_path, "A:value1\nB:value2\nC:value3\nD:value4");
 
-        let cache: Cache<String, String> = FilePersistence::read_file(3, file_path);
+        let cache: Cache<String, String> = FilePersistence::read_file(3, file_path).unwrap();
 
         assert_eq!(cache.elements.len(), 3);
         assert_eq!(cache.elements.contains_key("A"), false);
@@ -272,7 +491,7 @@ mod tests {
         cache.put("A".to_string(), "value_a".to_string());
         cache.put("B".to_string(), "value_b".to_string());
 
-        FilePersistence::write_file(&cache, file_path);
+        FilePersistence::write_file(&cache, file_path).unwrap();
 
         if let Ok(content) = read_to_string(file_path) {
             assert!(content.contains("A:value_a"));
@@ -283,7 +502,7 @@ mod tests {
     }
 
     #[test]
-    fn write_file_index_ordre() {
+    fn write_file_preserve_ordre_lru() {
         let file_path = "fichiers/test_write_ordre.txt";
         cleanup(file_path);
 
@@ -294,16 +513,106 @@ mod tests {
 
         let _ = cache_write.get(&"A".to_string());
 
-        test_elt_index(&cache_write, "A".to_string(), 4);
-        test_elt_index(&cache_write, "B".to_string(), 2);
-        test_elt_index(&cache_write, "C".to_string(), 3);
+        assert_lru_order(&cache_write, &["A", "C", "B"]);
+
+        FilePersistence::write_file(&cache_write, file_path).unwrap();
+        let cache_read: Cache<String, String> = FilePersistence::read_file(3, file_path).unwrap();
+
+        assert_lru_order(&cache_read, &["A", "C", "B"]);
+
+        cleanup(file_path);
+    }
+
+    #[test]
+    fn read_file_rejette_capacite_nulle() {
+        let file_path = "fichiers/test_capacite_nulle.txt";
+        cleanup(file_path);
+
+        let result: io::Result<Cache<String, String>> = FilePersistence::read_file(0, file_path);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+
+        cleanup(file_path);
+    }
+
+    #[test]
+    fn serde_persistence_json_round_trip_preserve_ordre_lru() {
+        let file_path = "fichiers/test_serde_json.txt";
+        cleanup(file_path);
+
+        let mut cache_write: Cache<String, String> = Cache::new(3);
+        cache_write.put("A".to_string(), "a:b".to_string());
+        cache_write.put("B".to_string(), "ligne1\nligne2".to_string());
+        cache_write.put("C".to_string(), "c".to_string());
+
+        let _ = cache_write.get(&"A".to_string());
+        assert_lru_order(&cache_write, &["A", "C", "B"]);
+
+        SerdePersistence::<Json>::write_file(&cache_write, file_path).unwrap();
+        let cache_read: Cache<String, String> =
+            SerdePersistence::<Json>::read_file(3, file_path).unwrap();
+
+        assert_lru_order(&cache_read, &["A", "C", "B"]);
+        test_elt_value(&cache_read, "A".to_string(), "a:b".to_string());
+        test_elt_value(&cache_read, "B".to_string(), "ligne1\nligne2".to_string());
+
+        cleanup(file_path);
+    }
+
+    #[test]
+    fn serde_persistence_json_pas_de_fichier() {
+        let file_path = "fichiers/test_serde_json_absent.txt";
+        cleanup(file_path);
+
+        let cache: Cache<String, String> = SerdePersistence::<Json>::read_file(3, file_path).unwrap();
+
+        assert_eq!(cache.elements.len(), 0);
+        assert!(std::path::Path::new(file_path).exists());
+
+        cleanup(file_path);
+    }
+
+    #[test]
+    fn serde_persistence_msgpack_round_trip_preserve_ordre_lru() {
+        // Le format binaire doit se comporter exactement comme Json : seul le codec change.
+        let file_path = "fichiers/test_serde_msgpack.txt";
+        cleanup(file_path);
+
+        let mut cache_write: Cache<String, String> = Cache::new(3);
+        cache_write.put("A".to_string(), "a:b".to_string());
+        cache_write.put("B".to_string(), "ligne1\nligne2".to_string());
+        cache_write.put("C".to_string(), "c".to_string());
+
+        let _ = cache_write.get(&"A".to_string());
+        assert_lru_order(&cache_write, &["A", "C", "B"]);
+
+        SerdePersistence::<MsgPack>::write_file(&cache_write, file_path).unwrap();
+        let cache_read: Cache<String, String> =
+            SerdePersistence::<MsgPack>::read_file(3, file_path).unwrap();
+
+        assert_lru_order(&cache_read, &["A", "C", "B"]);
+        test_elt_value(&cache_read, "A".to_string(), "a:b".to_string());
+        test_elt_value(&cache_read, "B".to_string(), "ligne1\nligne2".to_string());
+
+        cleanup(file_path);
+    }
+
+    #[test]
+    fn serde_persistence_cache_non_string_round_trip() {
+        // `SerdePersistence` ne dépend pas de `ToString`/`FromStr` comme `FilePersistence` :
+        // il peut donc persister des types qui n'ont pas de représentation textuelle naturelle.
+        let file_path = "fichiers/test_serde_non_string.txt";
+        cleanup(file_path);
+
+        let mut cache_write: Cache<u64, Vec<u8>> = Cache::new(2);
+        cache_write.put(1, vec![1, 2, 3]);
+        cache_write.put(2, vec![4, 5, 6]);
 
-        FilePersistence::write_file(&cache_write, file_path);
-        let cache_read: Cache<String, String> = FilePersistence::read_file(3, file_path);
+        SerdePersistence::<Json>::write_file(&cache_write, file_path).unwrap();
+        let mut cache_read: Cache<u64, Vec<u8>> =
+            SerdePersistence::<Json>::read_file(2, file_path).unwrap();
 
-        test_elt_index(&cache_read, "A".to_string(), 3);
-        test_elt_index(&cache_read, "B".to_string(), 1);
-        test_elt_index(&cache_read, "C".to_string(), 2);
+        assert_eq!(cache_read.get(&1), Some(&vec![1, 2, 3]));
+        assert_eq!(cache_read.get(&2), Some(&vec![4, 5, 6]));
 
         cleanup(file_path);
     }
@@ -0,0 +1,245 @@
+//! Module composant plusieurs caches en couches (`TieredCache`).
+//!
+//! Une utilisation courante est de placer un petit `Cache` en mémoire devant un cache
+//! plus grand adossé à un fichier (via `FilePersistence`/`SerdePersistence`) : les lectures
+//! passent d'abord par la couche rapide, et ne consultent les couches suivantes qu'en cas
+//! d'absence (`get`), en remontant alors la valeur trouvée dans les couches plus rapides
+//! (promotion, toujours write-through). Les écritures (`put`) suivent la `WritePolicy`
+//! choisie à la construction — voir [`WritePolicy::WriteBack`] : une entrée écrite dans ce
+//! mode rejoint les couches secondaires dès qu'elle est éjectée de `front`
+//! ([`CacheTrait::put_evicted`]), pas seulement à la prochaine lecture.
+
+use crate::cache::CacheTrait;
+use std::hash::Hash;
+
+/// Politique de propagation des écritures d'un [`TieredCache`] vers ses couches secondaires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WritePolicy {
+    /// `put` écrit immédiatement dans la couche rapide et dans toutes les couches secondaires.
+    WriteThrough,
+    /// `put` n'écrit que dans la couche rapide ; les couches secondaires ne reçoivent la
+    /// valeur que lorsque `front` l'évince pour faire de la place, via
+    /// [`CacheTrait::put_evicted`] (ou, comme pour toute `WritePolicy`, par promotion lors
+    /// d'un `get` qui la trouve déjà dans une couche secondaire). Réduit l'écriture vers les
+    /// couches secondaires aux seules entrées qui quittent réellement `front`, au prix d'un
+    /// délai avant qu'elles n'y apparaissent.
+    WriteBack,
+}
+
+/// Cache composé d'une couche rapide (`front`) et d'une ou plusieurs couches secondaires
+/// consultées dans l'ordre en cas d'absence dans `front`.
+///
+/// Un `get` qui échoue sur `front` mais trouve la clé dans une couche secondaire promeut
+/// la valeur dans `front` et dans les couches secondaires plus rapides que celle-ci
+/// (write-through sur la promotion, quelle que soit la `WritePolicy`).
+/// Un `put` écrit toujours dans `front`, et propage aux couches secondaires selon la
+/// `WritePolicy` choisie à la construction.
+///
+/// # Exemple
+///
+/// ```rust
+/// use lru_cache::cache::{Cache, CacheTrait};
+/// use lru_cache::tiered::{TieredCache, WritePolicy};
+///
+/// let front: Cache<String, String> = Cache::new(1);
+/// let backing: Cache<String, String> = Cache::new(3);
+/// let mut tiered = TieredCache::new(Box::new(front), WritePolicy::WriteThrough);
+/// tiered.add_tier(Box::new(backing));
+///
+/// tiered.put("A".to_string(), "value_a".to_string());
+/// tiered.put("B".to_string(), "value_b".to_string());
+/// // "A" a été éjecté de `front` (capacité 1) mais write-through l'a propagé à la couche secondaire.
+/// assert_eq!(tiered.get(&"A".to_string()), Some(&"value_a".to_string()));
+/// ```
+pub struct TieredCache<K, V> {
+    front: Box<dyn CacheTrait<K, V>>,
+    tiers: Vec<Box<dyn CacheTrait<K, V>>>,
+    policy: WritePolicy,
+}
+
+impl<K, V> TieredCache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    /// Crée un `TieredCache` avec `front` comme couche rapide et aucune couche secondaire.
+    /// Des couches secondaires peuvent ensuite être ajoutées avec [`TieredCache::add_tier`].
+    pub fn new(front: Box<dyn CacheTrait<K, V>>, policy: WritePolicy) -> Self {
+        Self {
+            front,
+            tiers: Vec::new(),
+            policy,
+        }
+    }
+
+    /// Enregistre une couche secondaire, consultée après `front` (et après les couches
+    /// déjà enregistrées) lors d'un `get`.
+    pub fn add_tier(&mut self, tier: Box<dyn CacheTrait<K, V>>) {
+        self.tiers.push(tier);
+    }
+
+    /// Récupère la valeur associée à `key`.
+    ///
+    /// Consulte d'abord `front`, puis chaque couche secondaire dans l'ordre d'enregistrement.
+    /// Une valeur trouvée dans une couche secondaire est promue dans `front` et dans les
+    /// couches secondaires consultées avant elle, avant d'être retournée. Avec `WriteBack`,
+    /// une entrée que cette promotion évince de `front` est écrite dans les couches secondaires.
+    /// Renvoie `None` si `key` n'est présente dans aucune couche.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        if self.front.get(key).is_some() {
+            return self.front.get(key);
+        }
+
+        for i in 0..self.tiers.len() {
+            if let Some(value) = self.tiers[i].get(key) {
+                let value = value.clone();
+                for faster in &mut self.tiers[..i] {
+                    faster.put(key.clone(), value.clone());
+                }
+                let (_, evicted) = self.front.put_evicted(key.clone(), value);
+                if let (WritePolicy::WriteBack, Some((evicted_key, evicted_value))) =
+                    (self.policy, evicted)
+                {
+                    for tier in &mut self.tiers {
+                        tier.put(evicted_key.clone(), evicted_value.clone());
+                    }
+                }
+                return self.front.get(key);
+            }
+        }
+
+        None
+    }
+
+    /// Insère ou met à jour `key`/`value` dans `front`. Renvoie l'ancienne valeur de `front`,
+    /// s'il y en avait une (comme [`CacheTrait::put`]).
+    ///
+    /// - Avec `WriteThrough`, la nouvelle valeur est aussi écrite immédiatement dans toutes
+    ///   les couches secondaires.
+    /// - Avec `WriteBack`, rien n'est propagé tout de suite ; si cette insertion évince une
+    ///   autre entrée de `front` pour faire de la place, l'entrée évincée est alors écrite
+    ///   dans les couches secondaires (voir [`WritePolicy::WriteBack`]).
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        let (old_value, evicted) = self.front.put_evicted(key.clone(), value.clone());
+
+        match self.policy {
+            WritePolicy::WriteThrough => {
+                for tier in &mut self.tiers {
+                    tier.put(key.clone(), value.clone());
+                }
+            }
+            WritePolicy::WriteBack => {
+                if let Some((evicted_key, evicted_value)) = evicted {
+                    for tier in &mut self.tiers {
+                        tier.put(evicted_key.clone(), evicted_value.clone());
+                    }
+                }
+            }
+        }
+
+        old_value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::Cache;
+
+    #[test]
+    fn test_get_consulte_les_couches_secondaires_dans_lordre() {
+        let front: Cache<String, String> = Cache::new(1);
+        let tier1: Cache<String, String> = Cache::new(1);
+        let mut tier2: Cache<String, String> = Cache::new(1);
+        tier2.put("A".to_string(), "value_a".to_string());
+
+        let mut tiered = TieredCache::new(Box::new(front), WritePolicy::WriteThrough);
+        tiered.add_tier(Box::new(tier1));
+        tiered.add_tier(Box::new(tier2));
+
+        // Absente de front et de la première couche secondaire, trouvée dans la seconde.
+        assert_eq!(tiered.get(&"A".to_string()), Some(&"value_a".to_string()));
+    }
+
+    #[test]
+    fn test_get_promeut_la_valeur_trouvee_dans_une_couche_secondaire() {
+        let front: Cache<String, String> = Cache::new(2);
+        let mut backing: Cache<String, String> = Cache::new(3);
+        backing.put("A".to_string(), "value_a".to_string());
+
+        let mut tiered = TieredCache::new(Box::new(front), WritePolicy::WriteThrough);
+        tiered.add_tier(Box::new(backing));
+
+        assert!(tiered.front.get_elt(&"A".to_string()).is_none());
+        assert_eq!(tiered.get(&"A".to_string()), Some(&"value_a".to_string()));
+        // "A" a bien été recopiée dans front, pas seulement renvoyée depuis la couche secondaire.
+        assert!(tiered.front.get_elt(&"A".to_string()).is_some());
+    }
+
+    #[test]
+    fn test_write_through_propage_a_toutes_les_couches() {
+        let front: Cache<String, String> = Cache::new(3);
+        let tier1: Cache<String, String> = Cache::new(3);
+        let tier2: Cache<String, String> = Cache::new(3);
+
+        let mut tiered = TieredCache::new(Box::new(front), WritePolicy::WriteThrough);
+        tiered.add_tier(Box::new(tier1));
+        tiered.add_tier(Box::new(tier2));
+
+        tiered.put("A".to_string(), "value_a".to_string());
+
+        assert!(tiered.front.get_elt(&"A".to_string()).is_some());
+        for tier in &tiered.tiers {
+            assert!(tier.get_elt(&"A".to_string()).is_some());
+        }
+    }
+
+    #[test]
+    fn test_write_back_ne_propage_pas_sans_eviction() {
+        let front: Cache<String, String> = Cache::new(3);
+        let backing: Cache<String, String> = Cache::new(3);
+
+        let mut tiered = TieredCache::new(Box::new(front), WritePolicy::WriteBack);
+        tiered.add_tier(Box::new(backing));
+
+        tiered.put("A".to_string(), "value_a".to_string());
+        // Pas encore propagée : la couche secondaire ignore tout de "A".
+        assert!(tiered.tiers[0].get_elt(&"A".to_string()).is_none());
+
+        // Une lecture qui trouve "A" dans front n'a pas besoin de descendre dans les couches
+        // secondaires, donc ne la propage pas non plus.
+        assert_eq!(tiered.get(&"A".to_string()), Some(&"value_a".to_string()));
+        assert!(tiered.tiers[0].get_elt(&"A".to_string()).is_none());
+    }
+
+    #[test]
+    fn test_write_back_propage_quand_front_evince_lentree() {
+        let front: Cache<String, String> = Cache::new(1);
+        let backing: Cache<String, String> = Cache::new(3);
+
+        let mut tiered = TieredCache::new(Box::new(front), WritePolicy::WriteBack);
+        tiered.add_tier(Box::new(backing));
+
+        tiered.put("A".to_string(), "value_a".to_string());
+        assert!(tiered.tiers[0].get_elt(&"A".to_string()).is_none());
+
+        // "A" est éjectée de front (capacité 1) pour faire de la place à "B" : l'éviction est
+        // flushée vers les couches secondaires, même si "A" n'a jamais été relue.
+        tiered.put("B".to_string(), "value_b".to_string());
+        assert!(tiered.tiers[0].get_elt(&"A".to_string()).is_some());
+        assert_eq!(tiered.get(&"A".to_string()), Some(&"value_a".to_string()));
+    }
+
+    #[test]
+    fn test_put_renvoie_lancienne_valeur_de_front() {
+        let front: Cache<String, String> = Cache::new(3);
+        let mut tiered = TieredCache::new(Box::new(front), WritePolicy::WriteThrough);
+
+        assert_eq!(tiered.put("A".to_string(), "value_a".to_string()), None);
+        assert_eq!(
+            tiered.put("A".to_string(), "value_a2".to_string()),
+            Some("value_a".to_string())
+        );
+        assert_eq!(tiered.get(&"A".to_string()), Some(&"value_a2".to_string()));
+    }
+}
@@ -0,0 +1,381 @@
+//! Module implémentant une politique d'admission 2Q (`TwoQueueCache`), une alternative
+//! au LRU pur résistante aux balayages (scans) : une clé qui n'est vue qu'une fois (cas
+//! typique d'un parcours séquentiel) n'évince jamais les clés réellement fréquentes, car
+//! elle n'est promue dans l'anneau LRU principal qu'après avoir été vue une seconde fois.
+//!
+//! Trois structures, dimensionnées à partir de la capacité totale, se partagent le travail :
+//! - `a1in` : une file FIFO des clés récemment insérées, *avec* leur valeur (par défaut ~25 %
+//!   de la capacité) ;
+//! - `a1out` : une file FIFO des clés récemment évincées de `a1in`, *sans* valeur (par défaut
+//!   ~50 % de la capacité), qui ne sert qu'à détecter une seconde visite ;
+//! - `am` : un anneau LRU classique ([`Cache`]) pour les clés fréquemment utilisées, avec leur valeur.
+
+use crate::cache::{Cache, CacheTrait, Element};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Cache à politique d'admission 2Q : une clé n'est promue dans l'anneau LRU principal
+/// (`am`) qu'après y avoir été vue deux fois, ce qui protège les clés fréquentes d'une
+/// éviction par un simple balayage de clés jamais réutilisées.
+///
+/// # Exemple
+///
+/// ```rust
+/// use lru_cache::cache::CacheTrait;
+/// use lru_cache::two_queue::TwoQueueCache;
+///
+/// let mut cache: TwoQueueCache<String, String> = TwoQueueCache::new(8);
+///
+/// // Premier put : "A" atterrit dans `a1in`, pas encore dans l'anneau LRU principal.
+/// cache.put("A".to_string(), "value_a".to_string());
+/// assert_eq!(cache.get(&"A".to_string()), Some(&"value_a".to_string()));
+///
+/// // Un balayage de clés jamais revues ne passe que par `a1in`/`a1out` et ne touche pas `am`.
+/// for i in 0..20 {
+///     cache.put(format!("scan-{i}"), "valeur de passage".to_string());
+/// }
+/// ```
+pub struct TwoQueueCache<K, V> {
+    a1in_capacity: usize,
+    a1out_capacity: usize,
+    a1in: VecDeque<K>,
+    a1in_values: HashMap<K, V>,
+    a1out: VecDeque<K>,
+    a1out_set: HashSet<K>,
+    am: Cache<K, V>,
+}
+
+impl<K, V> TwoQueueCache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    /// Retire `key` de `a1in` (file et table des valeurs), si elle s'y trouve.
+    fn remove_from_a1in(&mut self, key: &K) -> Option<V> {
+        let value = self.a1in_values.remove(key)?;
+        if let Some(pos) = self.a1in.iter().position(|k| k == key) {
+            self.a1in.remove(pos);
+        }
+        Some(value)
+    }
+
+    /// Insère `key` dans `a1in`, en évinçant vers `a1out` si `a1in` déborde, puis en
+    /// oubliant la plus ancienne clé de `a1out` si elle déborde à son tour.
+    ///
+    /// Renvoie l'entrée éjectée de `a1in`, s'il y en a une : sa valeur est alors perdue (seule
+    /// la clé survit, sans valeur, dans `a1out`), ce qui correspond à une éviction au sens de
+    /// [`CacheTrait::put_evicted`].
+    fn insert_into_a1in(&mut self, key: K, value: V) -> Option<(K, V)> {
+        self.a1in.push_back(key.clone());
+        self.a1in_values.insert(key, value);
+
+        let mut evicted = None;
+        if self.a1in.len() > self.a1in_capacity {
+            if let Some(evicted_key) = self.a1in.pop_front() {
+                if let Some(evicted_value) = self.a1in_values.remove(&evicted_key) {
+                    evicted = Some((evicted_key.clone(), evicted_value));
+                }
+                self.a1out.push_back(evicted_key.clone());
+                self.a1out_set.insert(evicted_key);
+            }
+        }
+
+        if self.a1out.len() > self.a1out_capacity {
+            if let Some(forgotten) = self.a1out.pop_front() {
+                self.a1out_set.remove(&forgotten);
+            }
+        }
+
+        evicted
+    }
+}
+
+impl<K, V> CacheTrait<K, V> for TwoQueueCache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    /// Crée un `TwoQueueCache` de capacité totale `size` : `a1in` reçoit le quart de `size`
+    /// (au moins 1), `a1out` la moitié de `size` (au moins 1, mais ne stocke que des clés
+    /// fantômes donc ne compte pas dans le total de valeurs résidentes), et `am` reçoit le
+    /// reste de `size` une fois la part de `a1in` déduite. Quand `a1in` consomme déjà toute
+    /// la capacité déclarée (`size <= 1`), `am` reçoit une capacité de 0 plutôt qu'un plancher
+    /// artificiel à 1 : voir [`TwoQueueCache::put`], qui évite alors d'y insérer quoi que ce
+    /// soit (un [`Cache`] de capacité 0 retient tout de même un élément une fois rempli, ce
+    /// qui romprait l'invariant `a1in` + `am` <= `size`). Les valeurs réellement résidentes
+    /// (`a1in` + `am`) restent donc bornées par `size` dès que `size >= 1`, comme pour un
+    /// [`Cache`] classique ; à `size == 0`, le plancher de `a1in` lui laisse tout de même une valeur.
+    fn new(size: usize) -> Self {
+        let a1in_capacity = (size / 4).max(1);
+        let a1out_capacity = (size / 2).max(1);
+        let am_capacity = size.saturating_sub(a1in_capacity);
+
+        Self {
+            a1in_capacity,
+            a1out_capacity,
+            a1in: VecDeque::new(),
+            a1in_values: HashMap::new(),
+            a1out: VecDeque::new(),
+            a1out_set: HashSet::new(),
+            am: Cache::new(am_capacity),
+        }
+    }
+
+    /// Récupère la valeur associée à `key`.
+    ///
+    /// Une clé trouvée dans `am` est replacée en tête (comme [`Cache::get`]). Une clé
+    /// trouvée dans `a1in` est renvoyée sans changer sa position dans la file (elle n'est
+    /// promue dans `am` qu'au prochain `put` qui la trouve dans `a1out`). Une clé présente
+    /// seulement dans `a1out` n'a pas de valeur : `get` renvoie alors `None`.
+    fn get(&mut self, key: &K) -> Option<&V> {
+        if self.am.contains(key) {
+            return self.am.get(key);
+        }
+
+        self.a1in_values.get(key)
+    }
+
+    /// Insère ou met à jour une paire clé-valeur.
+    ///
+    /// - Si `key` est déjà dans `am`, sa valeur est mise à jour et elle repasse en tête.
+    /// - Si `key` est déjà dans `a1in`, sa valeur est mise à jour et elle repasse en fin de
+    ///   file (la plus récente de `a1in`), sans être promue dans `am` (pas encore considérée
+    ///   comme fréquente).
+    /// - Si `key` est dans `a1out` (vue une première fois, puis évincée de `a1in`), elle est
+    ///   promue dans `am` avec la nouvelle valeur : c'est sa deuxième visite. Si `am` n'a
+    ///   aucune capacité réelle (`size <= 1`, voir [`TwoQueueCache::new`]), la valeur est
+    ///   réinsérée dans `a1in` à la place, pour ne pas dépasser la capacité totale déclarée.
+    /// - Sinon, `key` est une nouvelle clé : elle est insérée dans `a1in`.
+    fn put(&mut self, key: K, value: V) -> Option<V> {
+        self.put_evicted(key, value).0
+    }
+
+    /// Comme [`TwoQueueCache::put`], mais renvoie en plus l'entrée qui quitte le cache pour
+    /// faire de la place, le cas échéant : soit la plus ancienne entrée de `am` quand elle
+    /// déborde, soit la plus ancienne entrée de `a1in` quand elle est démue vers `a1out` (sa
+    /// valeur est alors perdue, seule la clé fantôme survit).
+    fn put_evicted(&mut self, key: K, value: V) -> (Option<V>, Option<(K, V)>) {
+        if self.am.contains(&key) {
+            return self.am.put_evicted(key, value);
+        }
+
+        if let Some(old_value) = self.remove_from_a1in(&key) {
+            self.a1in.push_back(key.clone());
+            self.a1in_values.insert(key, value);
+            return (Some(old_value), None);
+        }
+
+        if self.a1out_set.remove(&key) {
+            if let Some(pos) = self.a1out.iter().position(|k| k == &key) {
+                self.a1out.remove(pos);
+            }
+            if self.am.size == 0 {
+                return (None, self.insert_into_a1in(key, value));
+            }
+            let (_, evicted) = self.am.put_evicted(key, value);
+            return (None, evicted);
+        }
+
+        (None, self.insert_into_a1in(key, value))
+    }
+
+    /// Récupère un élément complet depuis `am` sans mettre à jour l'ordre LRU. Les entrées
+    /// résidant seulement dans `a1in` n'ont pas de représentation `Element` (pas de voisins
+    /// de liste chaînée) et renvoient donc `None` ici, même si [`TwoQueueCache::get`] les trouverait.
+    fn get_elt(&self, key: &K) -> Option<&Element<K, V>> {
+        self.am.get_elt(key)
+    }
+}
+
+impl<K, V> TwoQueueCache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    /// Matérialise les entrées résidentes (`am` puis `a1in`, des plus anciennes aux plus
+    /// récentes) dans un [`Cache`] classique, pour pouvoir les persister avec
+    /// `FilePersistence`/`SerdePersistence`. Les clés fantômes de `a1out` (qui n'ont pas de
+    /// valeur) ne sont jamais incluses.
+    pub fn to_cache(&self) -> Cache<K, V> {
+        let mut cache = Cache::new(self.am.size + self.a1in_capacity);
+
+        let mut oldest_to_newest = Vec::new();
+        let mut current = self.am.tail.clone();
+        while let Some(key) = current {
+            match self.am.elements.get(&key) {
+                Some(elt) => {
+                    oldest_to_newest.push((key, elt.value.clone()));
+                    current = elt.prev.clone();
+                }
+                None => break,
+            }
+        }
+
+        for key in &self.a1in {
+            if let Some(value) = self.a1in_values.get(key) {
+                oldest_to_newest.push((key.clone(), value.clone()));
+            }
+        }
+
+        for (key, value) in oldest_to_newest {
+            cache.put(key, value);
+        }
+
+        cache
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_two_queue_cache_vide() {
+        let cache: TwoQueueCache<String, String> = TwoQueueCache::new(8);
+        assert_eq!(cache.a1in_capacity, 2);
+        assert_eq!(cache.a1out_capacity, 4);
+        // am reçoit le reste de la capacité totale une fois la part de a1in déduite.
+        assert_eq!(cache.am.size, 6);
+    }
+
+    #[test]
+    fn test_premiere_visite_reste_dans_a1in() {
+        let mut cache: TwoQueueCache<String, String> = TwoQueueCache::new(8);
+
+        assert_eq!(cache.put("A".to_string(), "value_a".to_string()), None);
+
+        // Présente dans a1in, mais pas encore promue dans am.
+        assert_eq!(cache.get(&"A".to_string()), Some(&"value_a".to_string()));
+        assert!(cache.get_elt(&"A".to_string()).is_none());
+    }
+
+    #[test]
+    fn test_a1in_overflow_vers_a1out() {
+        let mut cache: TwoQueueCache<String, String> = TwoQueueCache::new(8);
+        // a1in_capacity == 2
+
+        cache.put("A".to_string(), "value_a".to_string());
+        cache.put("B".to_string(), "value_b".to_string());
+        cache.put("C".to_string(), "value_c".to_string());
+        // A, le plus ancien de a1in, est évincé vers a1out (sans sa valeur).
+
+        assert_eq!(cache.get(&"A".to_string()), None);
+        assert!(cache.a1out_set.contains("A"));
+        assert_eq!(cache.get(&"B".to_string()), Some(&"value_b".to_string()));
+        assert_eq!(cache.get(&"C".to_string()), Some(&"value_c".to_string()));
+    }
+
+    #[test]
+    fn test_deuxieme_visite_promeut_dans_am() {
+        let mut cache: TwoQueueCache<String, String> = TwoQueueCache::new(8);
+
+        cache.put("A".to_string(), "value_a".to_string());
+        cache.put("B".to_string(), "value_b".to_string());
+        cache.put("C".to_string(), "value_c".to_string());
+        // A est maintenant dans a1out (vue une première fois).
+
+        let old = cache.put("A".to_string(), "value_A_bis".to_string());
+        assert_eq!(old, None);
+
+        // Deuxième visite : A est désormais résidente dans am.
+        assert!(cache.get_elt(&"A".to_string()).is_some());
+        assert_eq!(
+            cache.get(&"A".to_string()),
+            Some(&"value_A_bis".to_string())
+        );
+    }
+
+    #[test]
+    fn test_scan_ne_pollue_pas_am() {
+        let mut cache: TwoQueueCache<String, String> = TwoQueueCache::new(8);
+
+        cache.put("A".to_string(), "value_a".to_string());
+        // A déborde de a1in (capacité 2) vers a1out après ces deux insertions.
+        cache.put("B".to_string(), "value_b".to_string());
+        cache.put("C".to_string(), "value_c".to_string());
+        // Deuxième visite de A (depuis a1out) : elle est promue dans am.
+        cache.put("A".to_string(), "value_a".to_string());
+        assert!(cache.get_elt(&"A".to_string()).is_some());
+
+        // Un balayage de clés jamais revues ne passe que par a1in/a1out.
+        for i in 0..20 {
+            cache.put(format!("scan-{i}"), "valeur de passage".to_string());
+        }
+
+        // A, dans am, survit au balayage.
+        assert_eq!(cache.get(&"A".to_string()), Some(&"value_a".to_string()));
+    }
+
+    #[test]
+    fn test_to_cache_contient_am_et_a1in() {
+        let mut cache: TwoQueueCache<String, String> = TwoQueueCache::new(8);
+
+        cache.put("A".to_string(), "value_a".to_string());
+        cache.put("B".to_string(), "value_b".to_string());
+        cache.put("C".to_string(), "value_c".to_string());
+        cache.put("A".to_string(), "value_a".to_string());
+        // A réside dans am après sa deuxième visite.
+        cache.put("D".to_string(), "value_d".to_string());
+        // D réside dans a1in.
+
+        let snapshot = cache.to_cache();
+        assert!(snapshot.get_elt(&"A".to_string()).is_some());
+        assert!(snapshot.get_elt(&"D".to_string()).is_some());
+    }
+
+    #[test]
+    fn test_residents_totaux_bornes_par_la_capacite_totale() {
+        // `am` est dimensionné sur ce qui reste de la capacité totale `n` une fois la part de
+        // `a1in` déduite (voir `CacheTrait::new`), pas sur `n` en entier : le nombre total de
+        // valeurs résidentes (`a1in` + `am`) reste donc borné par `n`, comme pour un `Cache`
+        // classique — `a1out` ne stocke que des clés fantômes et ne compte pas.
+        let mut cache: TwoQueueCache<String, String> = TwoQueueCache::new(3);
+        // a1in_capacity == 1, a1out_capacity == 1, am.size == 2.
+
+        // Chaque clé ci-dessous est évincée de a1in vers a1out par l'insertion suivante,
+        // puis promue dans am à sa deuxième visite (son retour depuis a1out).
+        cache.put("A".to_string(), "value_a".to_string());
+        cache.put("B".to_string(), "value_b".to_string());
+        cache.put("A".to_string(), "value_a".to_string());
+        // A promue dans am (capacité 2).
+
+        cache.put("C".to_string(), "value_c".to_string());
+        cache.put("B".to_string(), "value_b".to_string());
+        // B promue dans am : am = [B, A], déjà à sa capacité de 2.
+
+        cache.put("D".to_string(), "value_d".to_string());
+        cache.put("C".to_string(), "value_c".to_string());
+        // C promue dans am : am déborde, A (LRU de am) est évincée pour faire de la place.
+
+        assert!(cache.get_elt(&"A".to_string()).is_none());
+        assert!(cache.get_elt(&"B".to_string()).is_some());
+        assert!(cache.get_elt(&"C".to_string()).is_some());
+        assert!(cache.a1in_values.contains_key("D"));
+
+        let total_residents = cache.a1in_values.len() + cache.am.elements.len();
+        assert_eq!(total_residents, 3);
+        assert!(total_residents <= 3, "residents au-delà de la capacité totale déclarée");
+    }
+
+    #[test]
+    fn test_residents_bornes_quand_a1in_consomme_toute_la_capacite() {
+        // À size == 1, a1in_capacity consomme déjà toute la capacité totale : am ne doit
+        // recevoir aucune place réelle (am.size == 0), et une deuxième visite (promotion
+        // depuis a1out) doit retomber dans a1in plutôt que dans am, sans quoi un Cache de
+        // capacité 0 retiendrait tout de même un élément et romprait l'invariant.
+        let mut cache: TwoQueueCache<usize, usize> = TwoQueueCache::new(1);
+        assert_eq!(cache.am.size, 0);
+
+        cache.put(1, 1);
+        cache.put(2, 2);
+        cache.put(1, 1);
+        cache.put(3, 3);
+
+        let total_residents = cache.a1in_values.len() + cache.am.elements.len();
+        assert_eq!(total_residents, 1);
+        assert!(cache.am.elements.is_empty());
+        assert_eq!(cache.get(&3), Some(&3));
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.get(&2), None);
+    }
+}